@@ -54,7 +54,7 @@ fn build_or_fetch_plugin(
 
         // Check if wasm32-wasip1 target is installed
         let target_check = Command::new("rustup")
-            .args(&["target", "list", "--installed"])
+            .args(["target", "list", "--installed"])
             .output()?;
 
         let targets = String::from_utf8_lossy(&target_check.stdout);
@@ -65,7 +65,7 @@ fn build_or_fetch_plugin(
         }
 
         let status = Command::new("cargo")
-            .args(&[
+            .args([
                 "build",
                 "--release",
                 "--target",
@@ -73,6 +73,8 @@ fn build_or_fetch_plugin(
                 "--manifest-path",
                 &format!("{}/Cargo.toml", plugin_dir),
             ])
+            // Don't let `cargo clippy` on the CLI lint the plugin crates too
+            .env_remove("RUSTC_WORKSPACE_WRAPPER")
             .status()?;
 
         if !status.success() {
@@ -23,6 +23,9 @@ fn main() -> Result<()> {
                 print_help();
                 return Ok(());
             }
+            "doctor" => {
+                return doctor();
+            }
             _ => {}
         }
     }
@@ -48,6 +51,7 @@ fn print_help() {
 
 Usage:
   bunshin                    Launch Bunshin (auto-starts Claude)
+  bunshin doctor             Check your environment for problems
   bunshin --version          Show version
   bunshin --help             Show this help
 
@@ -136,6 +140,203 @@ fn which_zellij() -> Option<PathBuf> {
     which::which("zellij").ok()
 }
 
+struct Check {
+    name: &'static str,
+    passed: bool,
+    required: bool,
+    detail: String,
+    hint: &'static str,
+}
+
+fn doctor() -> Result<()> {
+    let checks = run_checks(&get_bunshin_dir()?);
+
+    println!("Bunshin (分身) doctor\n");
+    print!("{}", format_report(&checks));
+    println!();
+
+    let failed = failed_required(&checks);
+    if failed > 0 {
+        anyhow::bail!("{} required check(s) failed", failed);
+    }
+    println!("All required checks passed");
+    Ok(())
+}
+
+fn run_checks(bunshin_dir: &Path) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    // Zellij, and whether it matches the plugin API the WASM was built against
+    match which_zellij() {
+        Some(path) => {
+            checks.push(Check {
+                name: "zellij",
+                passed: true,
+                required: true,
+                detail: path.display().to_string(),
+                hint: "",
+            });
+            let version = Command::new(&path)
+                .arg("--version")
+                .output()
+                .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string())
+                .unwrap_or_default();
+            checks.push(Check {
+                name: "zellij version",
+                passed: version.ends_with(ZELLIJ_VERSION),
+                required: false,
+                detail: format!("{} (plugins built for {})", version, ZELLIJ_VERSION),
+                hint: "Plugins may fail to load; install the matching Zellij version",
+            });
+        }
+        None => checks.push(Check {
+            name: "zellij",
+            passed: false,
+            required: true,
+            detail: "not found in PATH".to_string(),
+            hint: "Install Zellij: cargo install zellij",
+        }),
+    }
+
+    // Claude Code is what every pane and session launches. Users with a
+    // custom install point layout.kdl at it instead of relying on PATH.
+    let layout = fs::read_to_string(bunshin_dir.join("config/layout.kdl")).ok();
+    let command = layout
+        .as_deref()
+        .and_then(layout_command)
+        .unwrap_or_else(|| "claude".to_string());
+    checks.push(claude_check(&command));
+
+    // ~/.bunshin must be writable for setup to extract files
+    let writable = fs::create_dir_all(bunshin_dir).and_then(|_| {
+        let probe = bunshin_dir.join(".doctor");
+        fs::write(&probe, b"")?;
+        fs::remove_file(&probe)
+    });
+    checks.push(Check {
+        name: "~/.bunshin writable",
+        passed: writable.is_ok(),
+        required: true,
+        detail: match writable {
+            Ok(()) => bunshin_dir.display().to_string(),
+            Err(e) => format!("{}: {}", bunshin_dir.display(), e),
+        },
+        hint: "Fix the permissions on ~/.bunshin",
+    });
+
+    // Setup never overwrites existing files, so plugins go stale after an upgrade
+    let plugin_dir = bunshin_dir.join("plugins");
+    for (name, path, embedded) in [
+        (
+            "bunshin plugin",
+            plugin_dir.join("bunshin.wasm"),
+            BUNSHIN_WASM,
+        ),
+        (
+            "status-bar plugin",
+            plugin_dir.join("status-bar.wasm"),
+            STATUS_BAR_WASM,
+        ),
+    ] {
+        let (passed, detail) = match fs::read(&path) {
+            Ok(bytes) if bytes == embedded => (true, path.display().to_string()),
+            Ok(_) => (false, format!("{} is out of date", path.display())),
+            Err(_) => (false, format!("{} is missing", path.display())),
+        };
+        checks.push(Check {
+            name,
+            passed,
+            required: false,
+            detail,
+            hint: "Delete ~/.bunshin/plugins and run `bunshin` to re-extract",
+        });
+    }
+
+    let config_dir = bunshin_dir.join("config");
+    for (name, path) in [
+        ("config", config_dir.join("config.kdl")),
+        ("layout", config_dir.join("layout.kdl")),
+    ] {
+        let passed = path.exists();
+        checks.push(Check {
+            name,
+            passed,
+            required: false,
+            detail: if passed {
+                path.display().to_string()
+            } else {
+                format!("{} is missing", path.display())
+            },
+            hint: "Run `bunshin` to extract the default file",
+        });
+    }
+
+    checks
+}
+
+/// The command the layout's Claude pane runs, e.g. `claude` or an absolute
+/// path to a custom install.
+fn layout_command(layout: &str) -> Option<String> {
+    layout.lines().find_map(|line| {
+        let rest = line.trim().strip_prefix("command ")?;
+        let rest = rest.trim().strip_prefix('"')?;
+        rest.split('"').next().map(str::to_string)
+    })
+}
+
+fn claude_check(command: &str) -> Check {
+    let custom = command != "claude";
+    match which::which(command) {
+        Ok(path) => Check {
+            name: "claude",
+            passed: true,
+            required: true,
+            detail: if custom {
+                format!("{} (from layout.kdl)", path.display())
+            } else {
+                path.display().to_string()
+            },
+            hint: "",
+        },
+        Err(_) if custom => Check {
+            name: "claude",
+            passed: false,
+            required: true,
+            detail: format!("{} (from layout.kdl) not found", command),
+            hint: "Fix the `command` path in ~/.bunshin/config/layout.kdl",
+        },
+        Err(_) => Check {
+            name: "claude",
+            passed: false,
+            required: true,
+            detail: "not found in PATH".to_string(),
+            hint: "Install Claude Code: https://claude.ai/download",
+        },
+    }
+}
+
+fn format_report(checks: &[Check]) -> String {
+    let mut report = String::new();
+    for check in checks {
+        let mark = if check.passed {
+            "✓"
+        } else if check.required {
+            "✗"
+        } else {
+            "!"
+        };
+        report.push_str(&format!("  {} {}: {}\n", mark, check.name, check.detail));
+        if !check.passed {
+            report.push_str(&format!("      {}\n", check.hint));
+        }
+    }
+    report
+}
+
+fn failed_required(checks: &[Check]) -> usize {
+    checks.iter().filter(|c| c.required && !c.passed).count()
+}
+
 fn create_config_file(path: &Path, plugin_path: &Path) -> Result<()> {
     let config = format!(
         r#"// Bunshin (分身) - Auto-generated Configuration
@@ -229,3 +430,65 @@ fn launch() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(passed: bool, required: bool) -> Check {
+        Check {
+            name: "test",
+            passed,
+            required,
+            detail: "detail".to_string(),
+            hint: "hint",
+        }
+    }
+
+    #[test]
+    fn test_layout_command() {
+        let dir = env::temp_dir().join(format!("bunshin-doctor-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("layout.kdl");
+        create_layout_file(&path, Path::new("/tmp/status-bar.wasm")).unwrap();
+        let layout = fs::read_to_string(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(layout_command(&layout).as_deref(), Some("claude"));
+
+        let custom = "pane {\n    command \"/opt/claude/bin/claude\"\n}\n";
+        assert_eq!(
+            layout_command(custom).as_deref(),
+            Some("/opt/claude/bin/claude")
+        );
+        assert_eq!(layout_command("pane {\n}\n"), None);
+    }
+
+    #[test]
+    fn test_claude_check_custom_path() {
+        let missing = claude_check("/nonexistent/bin/claude");
+        assert!(!missing.passed);
+        assert!(missing.required);
+        assert!(missing.detail.contains("layout.kdl"));
+
+        // Any executable stands in for a custom install
+        let exe = env::current_exe().unwrap();
+        let found = claude_check(exe.to_str().unwrap());
+        assert!(found.passed);
+    }
+
+    #[test]
+    fn test_report_and_failures() {
+        let checks = vec![check(true, true), check(false, false), check(false, true)];
+        let report = format_report(&checks);
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines[0], "  ✓ test: detail");
+        assert_eq!(lines[1], "  ! test: detail");
+        assert_eq!(lines[2], "      hint");
+        assert_eq!(lines[3], "  ✗ test: detail");
+        assert_eq!(lines.len(), 5);
+
+        // Only required checks fail the run
+        assert_eq!(failed_required(&checks), 1);
+        assert_eq!(failed_required(&checks[..2]), 0);
+    }
+}
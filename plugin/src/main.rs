@@ -1,4 +1,5 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 use zellij_tile::prelude::*;
 
 #[derive(Default)]
@@ -11,6 +12,16 @@ struct State {
     new_session_name: Option<String>,
     rename_input: Option<String>,
     error_message: Option<String>,
+    marked_sessions: BTreeSet<String>,
+    repo_name: Option<String>,
+    branch: Option<String>,
+    filter_query: String,
+    // Exited sessions Zellij can resurrect; their names are still taken
+    dead_sessions: Vec<String>,
+    permission_granted: bool,
+    // The suggestion last put in the create field, so a later git refresh
+    // can tell whether the user has edited it
+    suggested_name: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -19,6 +30,7 @@ enum Mode {
     Create,
     Rename,
     ConfirmKill,
+    Filter,
 }
 
 impl Default for Mode {
@@ -35,6 +47,8 @@ impl ZellijPlugin for State {
             EventType::Key,
             EventType::SessionUpdate,
             EventType::ModeUpdate,
+            EventType::PermissionRequestResult,
+            EventType::RunCommandResult,
         ]);
         request_permission(&[
             PermissionType::ReadApplicationState,
@@ -50,8 +64,13 @@ impl ZellijPlugin for State {
             Event::Key(key) => {
                 should_render = self.handle_key(key);
             }
-            Event::SessionUpdate(sessions, _dead_sessions) => {
+            Event::SessionUpdate(sessions, dead_sessions) => {
                 self.sessions = sessions;
+                self.dead_sessions = dead_sessions.into_iter().map(|(name, _)| name).collect();
+                // Drop marks for sessions that no longer exist
+                let sessions = &self.sessions;
+                self.marked_sessions
+                    .retain(|name| sessions.iter().any(|s| &s.name == name));
                 // Clamp selected index to valid range
                 if !self.sessions.is_empty() && self.selected_index >= self.sessions.len() {
                     self.selected_index = self.sessions.len() - 1;
@@ -62,6 +81,16 @@ impl ZellijPlugin for State {
                 self.colors = mode_info.style.colors;
                 should_render = true;
             }
+            Event::PermissionRequestResult(PermissionStatus::Granted) => {
+                self.permission_granted = true;
+                self.request_git_info();
+            }
+            Event::RunCommandResult(Some(0), stdout, _stderr, context)
+                if context.get("source").map(String::as_str) == Some("git-info") =>
+            {
+                self.set_git_info(&String::from_utf8_lossy(&stdout));
+                should_render = self.refresh_suggested_name();
+            }
             _ => {}
         }
         should_render
@@ -76,6 +105,7 @@ impl ZellijPlugin for State {
                 Mode::Create => self.render_create_session(rows, cols),
                 Mode::Rename => self.render_rename_session(rows, cols),
                 Mode::ConfirmKill => self.render_confirm_kill(rows, cols),
+                Mode::Filter => self.render_session_list(rows, cols),
             }
         }
     }
@@ -98,6 +128,7 @@ impl State {
             Mode::Create => self.handle_create_key(key),
             Mode::Rename => self.handle_rename_key(key),
             Mode::ConfirmKill => self.handle_confirm_kill_key(key),
+            Mode::Filter => self.handle_filter_key(key),
         }
     }
 
@@ -148,7 +179,13 @@ impl State {
             }
             BareKey::Char('c') if key.has_no_modifiers() => {
                 self.mode = Mode::Create;
-                self.new_session_name = Some(String::new());
+                let suggestion = self.suggested_session_name();
+                self.new_session_name = Some(suggestion.clone());
+                self.suggested_name = Some(suggestion);
+                // The branch may have changed since the plugin loaded
+                if self.permission_granted {
+                    self.request_git_info();
+                }
                 true
             }
             BareKey::Char('$') if key.has_no_modifiers() => {
@@ -161,8 +198,12 @@ impl State {
                     true
                 }
             }
+            BareKey::Char(' ') if key.has_no_modifiers() => {
+                self.toggle_mark_selected_session();
+                true
+            }
             BareKey::Char('x') if key.has_no_modifiers() => {
-                if !self.is_current_session_selected() {
+                if !self.marked_sessions.is_empty() || !self.is_current_session_selected() {
                     self.mode = Mode::ConfirmKill;
                     true
                 } else {
@@ -201,6 +242,11 @@ impl State {
             }
 
             // UI
+            BareKey::Char('/') if key.has_no_modifiers() => {
+                self.mode = Mode::Filter;
+                self.filter_query.clear();
+                true
+            }
             BareKey::Char('?') if key.has_no_modifiers() => {
                 self.show_help = true;
                 true
@@ -224,6 +270,10 @@ impl State {
                     }
                     true
                 }
+                BareKey::Char('u') if key.has_modifiers(&[KeyModifier::Ctrl]) => {
+                    name.clear();
+                    true
+                }
                 BareKey::Backspace if key.has_no_modifiers() => {
                     name.pop();
                     true
@@ -269,10 +319,89 @@ impl State {
         }
     }
 
+    fn handle_filter_key(&mut self, key: KeyWithModifier) -> bool {
+        match key.bare_key {
+            BareKey::Char(c) if key.has_no_modifiers() => {
+                self.filter_query.push(c);
+                self.select_first_match();
+                true
+            }
+            BareKey::Backspace if key.has_no_modifiers() => {
+                self.filter_query.pop();
+                self.select_first_match();
+                true
+            }
+            BareKey::Down => {
+                self.move_filtered_selection(true);
+                true
+            }
+            BareKey::Up => {
+                self.move_filtered_selection(false);
+                true
+            }
+            BareKey::Tab if key.has_no_modifiers() => {
+                if self.visible_sessions().contains(&self.selected_index) {
+                    self.toggle_mark_selected_session();
+                }
+                true
+            }
+            BareKey::Enter => {
+                if self.visible_sessions().contains(&self.selected_index) {
+                    self.switch_to_selected_session();
+                }
+                self.mode = Mode::List;
+                self.filter_query.clear();
+                true
+            }
+            BareKey::Esc => {
+                self.mode = Mode::List;
+                self.filter_query.clear();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Indices into `sessions` that match the filter query, in list order.
+    fn visible_sessions(&self) -> Vec<usize> {
+        (0..self.sessions.len())
+            .filter(|&i| fuzzy_match(&self.filter_query, &self.sessions[i].name))
+            .collect()
+    }
+
+    fn select_first_match(&mut self) {
+        if let Some(&first) = self.visible_sessions().first() {
+            self.selected_index = first;
+        }
+    }
+
+    fn move_filtered_selection(&mut self, forward: bool) {
+        let visible = self.visible_sessions();
+        if visible.is_empty() {
+            return;
+        }
+        let pos = visible
+            .iter()
+            .position(|&i| i == self.selected_index)
+            .unwrap_or(0);
+        let next = if forward {
+            (pos + 1) % visible.len()
+        } else if pos == 0 {
+            visible.len() - 1
+        } else {
+            pos - 1
+        };
+        self.selected_index = visible[next];
+    }
+
     fn handle_confirm_kill_key(&mut self, key: KeyWithModifier) -> bool {
         match key.bare_key {
             BareKey::Char('y') | BareKey::Char('Y') if key.has_no_modifiers() => {
-                self.kill_selected_session();
+                if self.marked_sessions.is_empty() {
+                    self.kill_selected_session();
+                } else {
+                    self.kill_marked_sessions();
+                }
                 self.mode = Mode::List;
                 true
             }
@@ -345,6 +474,12 @@ impl State {
                 self.new_session_name = None;
                 return true;
             }
+            if self.sessions.iter().any(|s| s.name == *name) || self.dead_sessions.contains(name) {
+                self.error_message = Some(format!("Session '{}' already exists", name));
+                self.mode = Mode::List;
+                self.new_session_name = None;
+                return true;
+            }
 
             switch_session(Some(name));
             self.mode = Mode::List;
@@ -374,6 +509,17 @@ impl State {
                 self.rename_input = None;
                 return true;
             }
+            if self
+                .sessions
+                .iter()
+                .any(|s| !s.is_current_session && s.name == *name)
+                || self.dead_sessions.contains(name)
+            {
+                self.error_message = Some(format!("Session '{}' already exists", name));
+                self.mode = Mode::List;
+                self.rename_input = None;
+                return true;
+            }
 
             rename_session(name);
             self.mode = Mode::List;
@@ -394,6 +540,86 @@ impl State {
         }
     }
 
+    fn toggle_mark_selected_session(&mut self) {
+        if let Some(session) = self.sessions.get(self.selected_index) {
+            if session.is_current_session {
+                self.error_message = Some("Cannot mark current session".to_string());
+            } else if !self.marked_sessions.remove(&session.name) {
+                self.marked_sessions.insert(session.name.clone());
+            }
+        }
+    }
+
+    fn kill_marked_sessions(&mut self) {
+        let names: Vec<String> = self.marked_sessions.iter().cloned().collect();
+        kill_sessions(&names);
+        self.marked_sessions.clear();
+    }
+
+    fn request_git_info(&mut self) {
+        // Fall back to the directory name when not inside a git repository
+        let cwd = get_plugin_ids().initial_cwd;
+        self.repo_name = cwd
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string());
+
+        let mut context = BTreeMap::new();
+        context.insert("source".to_string(), "git-info".to_string());
+        run_command_with_env_variables_and_cwd(
+            &[
+                "git",
+                "rev-parse",
+                "--show-toplevel",
+                "--abbrev-ref",
+                "HEAD",
+            ],
+            BTreeMap::new(),
+            cwd,
+            context,
+        );
+    }
+
+    fn set_git_info(&mut self, output: &str) {
+        let mut lines = output.lines();
+        if let Some(toplevel) = lines.next() {
+            if let Some(repo) = toplevel.trim().rsplit('/').next().filter(|r| !r.is_empty()) {
+                self.repo_name = Some(repo.to_string());
+            }
+        }
+        // A detached HEAD reports "HEAD", which makes a useless name
+        self.branch = lines
+            .next()
+            .map(|branch| branch.trim().to_string())
+            .filter(|branch| !branch.is_empty() && branch != "HEAD");
+    }
+
+    fn suggested_session_name(&self) -> String {
+        let existing: Vec<&str> = self
+            .sessions
+            .iter()
+            .map(|s| s.name.as_str())
+            .chain(self.dead_sessions.iter().map(String::as_str))
+            .collect();
+        suggest_session_name(
+            self.repo_name.as_deref(),
+            self.branch.as_deref(),
+            &chrono::Utc::now().format("%m%d").to_string(),
+            &existing,
+        )
+    }
+
+    /// Replace the create field with a fresh suggestion, unless the user has
+    /// edited it since it was filled in.
+    fn refresh_suggested_name(&mut self) -> bool {
+        if self.mode != Mode::Create || self.new_session_name != self.suggested_name {
+            return false;
+        }
+        let suggestion = self.suggested_session_name();
+        self.new_session_name = Some(suggestion.clone());
+        self.suggested_name = Some(suggestion);
+        true
+    }
+
     fn is_current_session_selected(&self) -> bool {
         self.sessions
             .get(self.selected_index)
@@ -476,22 +702,17 @@ impl State {
         // Headers
         let header_y = 3;
         let name_col = 2;
-        let windows_col = cols.saturating_sub(40);
-        let _panes_col = cols.saturating_sub(30);
-        let _clients_col = cols.saturating_sub(20);
+        let (name_width, show_details) = session_columns(cols);
 
-        let header = format!(
-            "{:<width1$}  {:<width2$}  {:<width3$}  {:<width4$}",
+        let header = session_row(
             "Session",
             "Windows",
             "Panes",
             "Clients",
-            width1 = windows_col.saturating_sub(name_col + 2).max(10),
-            width2 = 7,
-            width3 = 5,
-            width4 = 7,
+            name_width,
+            show_details,
         );
-        let header_text = Text::new(&header).color_range(1, 0..header.len());
+        let header_text = Text::new(&header).color_range(1, 0..header.chars().count());
         print_text_with_coordinates(header_text, name_col, header_y, None, None);
 
         // Separator
@@ -501,37 +722,44 @@ impl State {
         // Session list
         let list_start_y = header_y + 2;
         let max_visible_sessions = rows.saturating_sub(list_start_y + 3);
-
-        let start_idx = if self.selected_index >= max_visible_sessions {
-            self.selected_index.saturating_sub(max_visible_sessions - 1)
+        let visible = self.visible_sessions();
+        let selected_pos = visible
+            .iter()
+            .position(|&i| i == self.selected_index)
+            .unwrap_or(0);
+
+        let start_idx = if selected_pos >= max_visible_sessions {
+            selected_pos.saturating_sub(max_visible_sessions - 1)
         } else {
             0
         };
-        let end_idx = (start_idx + max_visible_sessions).min(self.sessions.len());
+        let end_idx = (start_idx + max_visible_sessions).min(visible.len());
 
-        for (i, session) in self.sessions[start_idx..end_idx].iter().enumerate() {
+        for (i, &global_idx) in visible[start_idx..end_idx].iter().enumerate() {
+            let session = &self.sessions[global_idx];
             let row = list_start_y + i;
-            let global_idx = start_idx + i;
             let is_selected = global_idx == self.selected_index;
             let is_current = session.is_current_session;
 
             let session_indicator = if is_current { "*" } else { " " };
-            let name_display = format!("{} {}", session_indicator, session.name);
+            let mark_indicator = if self.marked_sessions.contains(&session.name) {
+                "+"
+            } else {
+                " "
+            };
+            let name_display = format!("{}{} {}", mark_indicator, session_indicator, session.name);
 
             let windows_count = session.tabs.len();
             let panes_count: usize = session.panes.panes.len();
             let clients_count = session.connected_clients;
 
-            let line = format!(
-                "{:<width1$}  {:<width2$}  {:<width3$}  {:<width4$}",
-                name_display,
-                windows_count,
-                panes_count,
-                clients_count,
-                width1 = windows_col.saturating_sub(name_col + 2).max(10),
-                width2 = 7,
-                width3 = 5,
-                width4 = 7,
+            let line = session_row(
+                &name_display,
+                &windows_count.to_string(),
+                &panes_count.to_string(),
+                &clients_count.to_string(),
+                name_width,
+                show_details,
             );
 
             let mut text = Text::new(&line);
@@ -539,7 +767,11 @@ impl State {
                 text = text.selected();
             }
             if is_current {
-                text = text.color_range(2, 0..name_display.len());
+                let name_chars = fit_width(&name_display, name_width)
+                    .trim_end()
+                    .chars()
+                    .count();
+                text = text.color_range(2, 0..name_chars);
             }
 
             print_text_with_coordinates(text, name_col, row, None, None);
@@ -600,9 +832,13 @@ impl State {
         // Input
         let empty_string = String::new();
         let input = self.new_session_name.as_ref().unwrap_or(&empty_string);
-        let input_display = format!("{}_", input);
+        let mut input_display = format!("{}_", input);
+        // Scroll long names so the end being typed stays inside the box
+        while input_display.width() > box_width.saturating_sub(4) {
+            input_display.remove(0);
+        }
         print_text_with_coordinates(
-            Text::new(&input_display).color_range(2, 0..input_display.len()),
+            Text::new(&input_display).color_range(2, 0..input_display.chars().count()),
             box_x + 2,
             box_y + 4,
             None,
@@ -610,7 +846,7 @@ impl State {
         );
 
         // Help text
-        let help = "Enter: Create | Esc: Cancel";
+        let help = "Enter: Create | Ctrl+u: Clear | Esc: Cancel";
         print_text_with_coordinates(
             Text::new(help).color_range(0, 0..help.len()),
             box_x + (box_width.saturating_sub(help.len())) / 2,
@@ -732,15 +968,43 @@ impl State {
         }
 
         // Message
-        let msg = format!("Kill session '{}'?", session_name);
+        let (msg, highlight) = if self.marked_sessions.is_empty() {
+            (
+                format!("Kill session '{}'?", session_name),
+                14..14 + session_name.chars().count(),
+            )
+        } else {
+            let count = self.marked_sessions.len();
+            (
+                format!(
+                    "Kill {} marked session{}?",
+                    count,
+                    if count == 1 { "" } else { "s" }
+                ),
+                5..5 + count.to_string().len(),
+            )
+        };
         print_text_with_coordinates(
-            Text::new(&msg).color_range(1, 14..14 + session_name.len()),
-            box_x + (box_width.saturating_sub(msg.len())) / 2,
+            Text::new(&msg).color_range(1, highlight),
+            box_x + (box_width.saturating_sub(msg.width())) / 2,
             box_y + 3,
             None,
             None,
         );
 
+        // Which sessions are marked, so nothing is killed by surprise
+        if !self.marked_sessions.is_empty() {
+            let names: Vec<&str> = self.marked_sessions.iter().map(String::as_str).collect();
+            let summary = summarize_names(&names, box_width.saturating_sub(4));
+            print_text_with_coordinates(
+                Text::new(&summary).color_range(1, 0..summary.chars().count()),
+                box_x + (box_width.saturating_sub(summary.width())) / 2,
+                box_y + 4,
+                None,
+                None,
+            );
+        }
+
         // Help text
         let help = "y: Yes | n: No | Esc: Cancel";
         print_text_with_coordinates(
@@ -782,10 +1046,12 @@ impl State {
             "  Enter        Switch to selected session",
             "  c            Create new session",
             "  $            Rename current session",
-            "  x            Kill selected session",
+            "  Space        Mark/unmark session for kill",
+            "  x            Kill marked (or selected) sessions",
             "  d            Detach from session",
             "  (            Switch to previous session",
             "  )            Switch to next session",
+            "  /            Filter sessions (fuzzy, Tab marks)",
             "",
             "CLAUDE CODE ORCHESTRATION",
             "  C            Launch Claude in new pane",
@@ -826,10 +1092,25 @@ impl State {
     }
 
     fn render_status_line(&self, rows: usize, cols: usize) {
-        let status = format!("{} sessions", self.sessions.len());
+        let status = if self.mode == Mode::Filter {
+            format!(
+                "/{}_  ({} of {} sessions)",
+                self.filter_query,
+                self.visible_sessions().len(),
+                self.sessions.len()
+            )
+        } else if self.marked_sessions.is_empty() {
+            format!("{} sessions", self.sessions.len())
+        } else {
+            format!(
+                "{} sessions, {} marked",
+                self.sessions.len(),
+                self.marked_sessions.len()
+            )
+        };
         print_text_with_coordinates(
-            Text::new(&status).color_range(0, 0..status.len()),
-            (cols.saturating_sub(status.len())) / 2,
+            Text::new(&status).color_range(0, 0..status.chars().count()),
+            (cols.saturating_sub(status.width())) / 2,
             rows - 2,
             None,
             None,
@@ -839,8 +1120,8 @@ impl State {
     fn render_error(&self, error: &str, rows: usize, cols: usize) {
         let error_text = format!("Error: {}", error);
         print_text_with_coordinates(
-            Text::new(&error_text).color_range(1, 0..error_text.len()),
-            (cols.saturating_sub(error_text.len().min(cols - 4))) / 2,
+            Text::new(&error_text).color_range(1, 0..error_text.chars().count()),
+            (cols.saturating_sub(error_text.width().min(cols - 4))) / 2,
             rows - 3,
             None,
             None,
@@ -848,6 +1129,151 @@ impl State {
     }
 }
 
+/// Case-insensitive subsequence match, so `wfa` matches `webapp-fix-auth`.
+fn fuzzy_match(query: &str, name: &str) -> bool {
+    let mut name_chars = name.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|q| name_chars.any(|n| n == q))
+}
+
+/// Longest suggested name, in display cells. Leaves room for a `-N` suffix
+/// and the cursor in the create dialog's input.
+const MAX_SUGGESTION_WIDTH: usize = 40;
+
+/// Longest suggested name in bytes, well under Zellij's 108-byte limit even
+/// with a `-N` suffix.
+const MAX_SUGGESTION_BYTES: usize = 100;
+
+/// Build a session name like `webapp-fix-auth-0612` from the repository,
+/// branch and date, adding a numeric suffix if the name is already taken.
+fn suggest_session_name(
+    repo: Option<&str>,
+    branch: Option<&str>,
+    date: &str,
+    existing: &[&str],
+) -> String {
+    let joined = [repo, branch, Some(date)]
+        .iter()
+        .flatten()
+        .map(|part| {
+            part.chars()
+                .map(|c| {
+                    if c.is_alphanumeric() || c == '_' {
+                        c
+                    } else {
+                        '-'
+                    }
+                })
+                .collect::<String>()
+                .to_lowercase()
+        })
+        .collect::<Vec<_>>()
+        .join("-");
+
+    let mut base = String::new();
+    for c in joined.chars() {
+        if base.width() + c.width().unwrap_or(0) > MAX_SUGGESTION_WIDTH
+            || base.len() + c.len_utf8() > MAX_SUGGESTION_BYTES
+        {
+            break;
+        }
+        base.push(c);
+    }
+    let base = base.trim_end_matches('-').to_string();
+
+    let mut name = base.clone();
+    let mut suffix = 2;
+    while existing.contains(&name.as_str()) {
+        name = format!("{}-{}", base, suffix);
+        suffix += 1;
+    }
+    name
+}
+
+/// Narrowest name column we accept before dropping the Panes/Clients columns.
+const MIN_NAME_WIDTH: usize = 20;
+
+/// Work out the name column width for a terminal `cols` wide, and whether the
+/// Panes/Clients columns fit alongside it. Without details the name column is
+/// capped at `MIN_NAME_WIDTH`, so it never shrinks as the terminal widens.
+fn session_columns(cols: usize) -> (usize, bool) {
+    // Two cells of margin on each side of the table
+    let available = cols.saturating_sub(4);
+    // "  Windows" is 9 cells; adding "  Panes  Clients" makes it 25
+    let with_details = available.saturating_sub(25);
+    if with_details >= MIN_NAME_WIDTH {
+        (with_details, true)
+    } else {
+        (available.saturating_sub(9).clamp(10, MIN_NAME_WIDTH), false)
+    }
+}
+
+/// List the first few `names`, with a "+K more" count for the rest, in at
+/// most `width` display cells.
+fn summarize_names(names: &[&str], width: usize) -> String {
+    let shown = names.len().min(3);
+    let more = if names.len() > shown {
+        format!(" +{} more", names.len() - shown)
+    } else {
+        String::new()
+    };
+    let listed = names[..shown].join(", ");
+    let room = width.saturating_sub(more.width());
+    if listed.width() > room {
+        format!("{}{}", fit_width(&listed, room).trim_end(), more)
+    } else {
+        format!("{}{}", listed, more)
+    }
+}
+
+/// Format one row of the session table, with the name column fitted to
+/// `name_width` display cells.
+fn session_row(
+    name: &str,
+    windows: &str,
+    panes: &str,
+    clients: &str,
+    name_width: usize,
+    show_details: bool,
+) -> String {
+    if show_details {
+        format!(
+            "{}  {:<7}  {:<5}  {:<7}",
+            fit_width(name, name_width),
+            windows,
+            panes,
+            clients
+        )
+    } else {
+        format!("{}  {:<7}", fit_width(name, name_width), windows)
+    }
+}
+
+/// Truncate (with an ellipsis) or pad `text` so it occupies exactly `width`
+/// terminal cells. Wide characters such as CJK count as two cells.
+fn fit_width(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return format!("{}{}", text, " ".repeat(width - text.width()));
+    }
+
+    let mut fitted = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if used + char_width + 1 > width {
+            break;
+        }
+        fitted.push(c);
+        used += char_width;
+    }
+    fitted.push('…');
+    used += 1;
+    fitted.push_str(&" ".repeat(width.saturating_sub(used)));
+    fitted
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -869,6 +1295,215 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fuzzy_match() {
+        assert!(fuzzy_match("", "anything"));
+        assert!(fuzzy_match("wfa", "webapp-fix-auth"));
+        assert!(fuzzy_match("WEB", "webapp"));
+        assert!(fuzzy_match("分身", "分-身"));
+        assert!(!fuzzy_match("afw", "webapp-fix-auth"));
+        assert!(!fuzzy_match("webappx", "webapp"));
+    }
+
+    #[test]
+    fn test_filter_mode() {
+        let mut state = State::default();
+        state.sessions = vec![
+            create_test_session("frontend", true),
+            create_test_session("backend", false),
+            create_test_session("docs", false),
+            create_test_session("bench", false),
+        ];
+
+        state.handle_list_key(KeyWithModifier::new(BareKey::Char('/')));
+        assert_eq!(state.mode, Mode::Filter);
+
+        // Typing selects the first match
+        state.handle_filter_key(KeyWithModifier::new(BareKey::Char('b')));
+        assert_eq!(state.visible_sessions(), vec![1, 3]);
+        assert_eq!(state.selected_index, 1);
+
+        // Arrows move among matches only, wrapping around
+        state.handle_filter_key(KeyWithModifier::new(BareKey::Down));
+        assert_eq!(state.selected_index, 3);
+        state.handle_filter_key(KeyWithModifier::new(BareKey::Down));
+        assert_eq!(state.selected_index, 1);
+        state.handle_filter_key(KeyWithModifier::new(BareKey::Up));
+        assert_eq!(state.selected_index, 3);
+
+        state.handle_filter_key(KeyWithModifier::new(BareKey::Char('k')));
+        assert_eq!(state.visible_sessions(), vec![1]);
+        assert_eq!(state.selected_index, 1);
+
+        state.handle_filter_key(KeyWithModifier::new(BareKey::Backspace));
+        assert_eq!(state.filter_query, "b");
+
+        // Tab marks while the filter stays active
+        state.handle_filter_key(KeyWithModifier::new(BareKey::Tab));
+        state.handle_filter_key(KeyWithModifier::new(BareKey::Down));
+        state.handle_filter_key(KeyWithModifier::new(BareKey::Tab));
+        assert_eq!(state.mode, Mode::Filter);
+        assert_eq!(state.filter_query, "b");
+        assert!(state.marked_sessions.contains("backend"));
+        assert!(state.marked_sessions.contains("bench"));
+        assert_eq!(state.marked_sessions.len(), 2);
+
+        // Esc clears the filter
+        state.handle_filter_key(KeyWithModifier::new(BareKey::Esc));
+        assert_eq!(state.mode, Mode::List);
+        assert!(state.filter_query.is_empty());
+        assert_eq!(state.visible_sessions().len(), 4);
+    }
+
+    #[test]
+    fn test_suggest_session_name() {
+        assert_eq!(
+            suggest_session_name(Some("webapp"), Some("fix/auth"), "0612", &[]),
+            "webapp-fix-auth-0612"
+        );
+        assert_eq!(
+            suggest_session_name(Some("My App"), None, "0612", &[]),
+            "my-app-0612"
+        );
+        assert_eq!(suggest_session_name(None, None, "0612", &[]), "0612");
+
+        // Taken names get a numeric suffix
+        assert_eq!(
+            suggest_session_name(
+                Some("webapp"),
+                Some("main"),
+                "0612",
+                &["webapp-main-0612", "webapp-main-0612-2"]
+            ),
+            "webapp-main-0612-3"
+        );
+
+        // Long names fit the input box and stay under the byte limit
+        let long_branch = "feature/".repeat(20);
+        let name = suggest_session_name(Some("webapp"), Some(&long_branch), "0612", &[]);
+        assert!(name.width() <= MAX_SUGGESTION_WIDTH);
+        assert!(!name.ends_with('-'));
+        let wide = "分身".repeat(40);
+        let name = suggest_session_name(Some(&wide), None, "0612", &[name.as_str()]);
+        assert!(name.width() <= MAX_SUGGESTION_WIDTH);
+        assert!(name.len() < 108);
+    }
+
+    #[test]
+    fn test_set_git_info() {
+        let mut state = State::default();
+        state.set_git_info("/home/me/src/webapp\nfix/auth\n");
+        assert_eq!(state.repo_name.as_deref(), Some("webapp"));
+        assert_eq!(state.branch.as_deref(), Some("fix/auth"));
+
+        // Detached HEAD keeps the repo but drops the branch
+        state.set_git_info("/home/me/src/webapp\nHEAD\n");
+        assert_eq!(state.repo_name.as_deref(), Some("webapp"));
+        assert!(state.branch.is_none());
+    }
+
+    #[test]
+    fn test_create_prefills_suggestion() {
+        let mut state = State::default();
+        state.sessions = vec![create_test_session("session1", true)];
+        state.repo_name = Some("webapp".to_string());
+        state.branch = Some("main".to_string());
+
+        state.handle_list_key(KeyWithModifier::new(BareKey::Char('c')));
+        let name = state.new_session_name.clone().unwrap();
+        assert!(name.starts_with("webapp-main-"));
+
+        // An exited session with the suggested name pushes it to a suffix
+        state.handle_create_key(KeyWithModifier::new(BareKey::Esc));
+        state.dead_sessions = vec![name.clone()];
+        state.handle_list_key(KeyWithModifier::new(BareKey::Char('c')));
+        assert_eq!(state.new_session_name, Some(format!("{}-2", name)));
+
+        // Ctrl+u clears the suggestion for a fresh name
+        state.handle_create_key(KeyWithModifier::new(BareKey::Char('u')).with_ctrl_modifier());
+        assert_eq!(state.new_session_name.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_git_refresh_updates_unedited_suggestion() {
+        let git_result = |stdout: &str| {
+            let mut context = BTreeMap::new();
+            context.insert("source".to_string(), "git-info".to_string());
+            Event::RunCommandResult(Some(0), stdout.as_bytes().to_vec(), vec![], context)
+        };
+        let mut state = State::default();
+        state.sessions = vec![create_test_session("session1", true)];
+        state.repo_name = Some("webapp".to_string());
+        state.branch = Some("main".to_string());
+
+        state.handle_list_key(KeyWithModifier::new(BareKey::Char('c')));
+        state.update(git_result("/src/webapp\nfix/auth\n"));
+        let name = state.new_session_name.clone().unwrap();
+        assert!(name.starts_with("webapp-fix-auth-"));
+
+        // Once edited, the field is left alone
+        state.handle_create_key(KeyWithModifier::new(BareKey::Char('x')));
+        state.update(git_result("/src/webapp\nmain\n"));
+        assert_eq!(state.new_session_name, Some(format!("{}x", name)));
+    }
+
+    #[test]
+    fn test_summarize_names() {
+        assert_eq!(summarize_names(&["api"], 40), "api");
+        assert_eq!(
+            summarize_names(&["api", "docs", "web", "worker", "cron"], 40),
+            "api, docs, web +2 more"
+        );
+
+        // Names are truncated before the count is
+        let summary = summarize_names(&["a-very-long-session-name", "b", "c", "d"], 20);
+        assert!(summary.width() <= 20);
+        assert!(summary.ends_with(" +1 more"));
+    }
+
+    #[test]
+    fn test_fit_width() {
+        // Padding
+        assert_eq!(fit_width("abc", 5), "abc  ");
+        assert_eq!(fit_width("分身", 5), "分身 ");
+        assert_eq!(fit_width("abc", 3), "abc");
+
+        // Truncation
+        assert_eq!(fit_width("abcdef", 4), "abc…");
+        assert_eq!(fit_width("分身の術", 6), "分身… ");
+        assert_eq!(fit_width("分身の術", 5), "分身…");
+
+        for text in ["abcdef", "分身の術", "a分b身c"] {
+            for width in 1..8 {
+                assert_eq!(fit_width(text, width).width(), width.max(1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_session_row_narrow_drops_details() {
+        let wide = session_row("main", "1", "2", "3", 10, true);
+        assert!(wide.contains("2") && wide.contains("3"));
+
+        let narrow = session_row("main", "1", "2", "3", 10, false);
+        assert_eq!(narrow.trim_end(), "main        1");
+    }
+
+    #[test]
+    fn test_session_columns_never_shrink() {
+        let mut prev = 0;
+        for cols in 40..200 {
+            let (name_width, show_details) = session_columns(cols);
+            assert!(name_width >= prev, "name column shrank at {} cols", cols);
+            assert!(name_width >= 10);
+            let row = session_row("x", "1", "2", "3", name_width, show_details);
+            assert!(row.width() <= cols - 4, "row overflows at {} cols", cols);
+            prev = name_width;
+        }
+        assert!(!session_columns(48).1);
+        assert_eq!(session_columns(49), (MIN_NAME_WIDTH, true));
+    }
+
     #[test]
     fn test_state_default() {
         let state = State::default();
@@ -879,6 +1514,7 @@ mod tests {
         assert!(state.new_session_name.is_none());
         assert!(state.rename_input.is_none());
         assert!(state.error_message.is_none());
+        assert!(state.marked_sessions.is_empty());
     }
 
     #[test]
@@ -1157,6 +1793,54 @@ mod tests {
         assert_eq!(state.mode, Mode::List);
     }
 
+    #[test]
+    fn test_mark_sessions_for_kill() {
+        let mut state = State::default();
+        state.sessions = vec![
+            create_test_session("session1", true),
+            create_test_session("session2", false),
+            create_test_session("session3", false),
+        ];
+
+        // Current session cannot be marked
+        state.handle_list_key(KeyWithModifier::new(BareKey::Char(' ')));
+        assert!(state.marked_sessions.is_empty());
+        assert!(state.error_message.is_some());
+        state.error_message = None;
+
+        state.selected_index = 1;
+        state.handle_list_key(KeyWithModifier::new(BareKey::Char(' ')));
+        state.selected_index = 2;
+        state.handle_list_key(KeyWithModifier::new(BareKey::Char(' ')));
+        assert_eq!(state.marked_sessions.len(), 2);
+
+        // Space again unmarks
+        state.handle_list_key(KeyWithModifier::new(BareKey::Char(' ')));
+        assert_eq!(state.marked_sessions.len(), 1);
+        assert!(state.marked_sessions.contains("session2"));
+
+        // With marks, 'x' confirms even while the current session is selected
+        state.selected_index = 0;
+        state.handle_list_key(KeyWithModifier::new(BareKey::Char('x')));
+        assert_eq!(state.mode, Mode::ConfirmKill);
+        assert!(state.error_message.is_none());
+    }
+
+    #[test]
+    fn test_session_update_drops_stale_marks() {
+        let mut state = State::default();
+        state.sessions = vec![
+            create_test_session("session1", true),
+            create_test_session("session2", false),
+        ];
+        state.marked_sessions.insert("session2".to_string());
+
+        let event = Event::SessionUpdate(vec![create_test_session("session1", true)], vec![]);
+        state.update(event);
+
+        assert!(state.marked_sessions.is_empty());
+    }
+
     #[test]
     fn test_session_update_clamps_index() {
         let mut state = State::default();
@@ -1253,4 +1937,64 @@ mod tests {
         assert!(state.error_message.is_some());
         assert!(state.error_message.as_ref().unwrap().contains("too long"));
     }
+
+    #[test]
+    fn test_create_rejects_existing_session_name() {
+        let mut state = State::default();
+        state.sessions = vec![
+            create_test_session("session1", true),
+            create_test_session("session2", false),
+        ];
+        state.dead_sessions = vec!["exited".to_string()];
+
+        for existing in ["session1", "session2", "exited"] {
+            state.mode = Mode::Create;
+            state.new_session_name = Some(existing.to_string());
+            state.create_session();
+            assert_eq!(state.mode, Mode::List);
+            assert!(state.new_session_name.is_none());
+            assert!(state
+                .error_message
+                .as_ref()
+                .unwrap()
+                .contains("already exists"));
+            state.error_message = None;
+        }
+    }
+
+    #[test]
+    fn test_rename_rejects_existing_session_name() {
+        let mut state = State::default();
+        state.sessions = vec![
+            create_test_session("session1", true),
+            create_test_session("session2", false),
+        ];
+        state.mode = Mode::Rename;
+
+        state.rename_input = Some("session2".to_string());
+        state.rename_session();
+        assert_eq!(state.mode, Mode::List);
+        assert!(state.rename_input.is_none());
+        assert!(state.error_message.is_some());
+        assert!(state
+            .error_message
+            .as_ref()
+            .unwrap()
+            .contains("already exists"));
+
+        // Exited sessions still hold their names
+        state.error_message = None;
+        state.update(Event::SessionUpdate(
+            state.sessions.clone(),
+            vec![("exited".to_string(), std::time::Duration::from_secs(60))],
+        ));
+        state.mode = Mode::Rename;
+        state.rename_input = Some("exited".to_string());
+        state.rename_session();
+        assert!(state
+            .error_message
+            .as_ref()
+            .unwrap()
+            .contains("already exists"));
+    }
 }